@@ -2,8 +2,11 @@
 
 //
 
-use core::ops::{Add, Div, Mul, Range, Sub};
-use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use core::ops::{Add, Bound, Div, Mul, Range, RangeBounds, RangeInclusive, Sub};
+use num_traits::{
+    AsPrimitive, Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, SaturatingAdd,
+    SaturatingMul, SaturatingSub,
+};
 
 //
 
@@ -62,9 +65,142 @@ use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
 /// // panics
 /// let _ = 200_u8.map_range(0..10, 0..20);
 /// ```
+///
+/// Implemented for any `T: Copy + Add + Sub + Mul + Div`, so this works for
+/// custom numeric types (fixed-point, newtypes, bignums, ...) as well as the
+/// primitives. `i8..=i128`, `u8..=u128`, `f32` and `f64` additionally have a
+/// `const fn` equivalent — [`map_range_i32`] and friends — for evaluating the
+/// same formula in `const` contexts, where a trait method can't be called;
+/// those free functions duplicate the formula rather than backing this
+/// trait's impl, since Rust has no stable specialization to let a blanket
+/// impl and per-type impls coexist.
 pub trait MapRange: Sized {
     #[must_use]
     fn map_range(self, from: Range<Self>, to: Range<Self>) -> Self;
+
+    /// Same as [`Self::map_range`] but for closed (inclusive) ranges.
+    ///
+    /// `a..=b` has `b - a + 1` representable steps, so a naive caller would
+    /// widen both ends by one and call [`Self::map_range`] — which panics
+    /// the moment `b` is the type's max value. This avoids ever forming
+    /// `b + 1` by instead forwarding the inclusive endpoints unmodified as
+    /// a half-open range: numerically that's the same computation as
+    /// `map_range(a..b, c..d)`, which still reaches `to.end()` exactly when
+    /// `self == from.end()` (`self - from.start` cancels against the
+    /// identical span in the denominator), so the `+ 1` span is never
+    /// actually formed — it only motivates why widening by one would have
+    /// been wrong.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use map_range::MapRange;
+    /// assert_eq!(9_u8.map_range_inclusive(0..=9, 0..=20), 20);
+    /// assert_eq!(0_u8.map_range_inclusive(0..=9, 0..=20), 0);
+    /// ```
+    #[must_use]
+    fn map_range_inclusive(self, from: RangeInclusive<Self>, to: RangeInclusive<Self>) -> Self {
+        let (from_start, from_end) = from.into_inner();
+        let (to_start, to_end) = to.into_inner();
+        self.map_range(from_start..from_end, to_start..to_end)
+    }
+
+    /// Same as [`Self::map_range`] but accepts any [`RangeBounds`], resolving
+    /// unbounded sides to [`Bounded::min_value`] / [`Bounded::max_value`].
+    ///
+    /// A bound's end is passed through as-is regardless of whether it's
+    /// [`Bound::Included`] or [`Bound::Excluded`] — [`Self::map_range_inclusive`]
+    /// already reaches an included end exactly by forwarding it unmodified, and
+    /// an excluded end (as in a plain [`Range`]) is exactly the value
+    /// [`Self::map_range`] expects for its half-open `end`. Widening an excluded
+    /// end by subtracting one (as an inclusive *count* would require) would
+    /// both disagree with [`Self::map_range`] on integers and be meaningless
+    /// for floats, which have no discrete "last value before the end".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use map_range::MapRange;
+    /// assert_eq!(0_u8.map_range_bounds(.., 0..=20), 0);
+    /// assert_eq!(9_u8.map_range_bounds(0..10, 0..20), 9_u8.map_range(0..10, 0..20));
+    /// assert_eq!(5.0_f32.map_range_bounds(0.0..10.0, 0.0..1.0), 5.0_f32.map_range(0.0..10.0, 0.0..1.0));
+    /// ```
+    #[must_use]
+    fn map_range_bounds<F, T>(self, from: F, to: T) -> Self
+    where
+        Self: Copy + One + Bounded + Add<Output = Self>,
+        F: RangeBounds<Self>,
+        T: RangeBounds<Self>,
+    {
+        let from = resolve_start(from.start_bound())..=resolve_end(from.end_bound());
+        let to = resolve_start(to.start_bound())..=resolve_end(to.end_bound());
+        self.map_range_inclusive(from, to)
+    }
+
+    /// Yields `steps` values evenly spaced through `from`, each mapped into
+    /// `to`, without allocating.
+    ///
+    /// The first item is exactly `to.start` and the last is exactly
+    /// `to.end` — those two are special-cased rather than computed through
+    /// the general formula, since for floats `from_start + (from_end -
+    /// from_start) * (steps - 1) / (steps - 1)` isn't guaranteed to round
+    /// back to exactly `from_end`, and re-mapping that into `to` could then
+    /// land an ULP off `to.end`. Every other source point is computed from
+    /// its index rather than by repeated addition, so there's no
+    /// accumulated floating-point drift over long sequences (useful for
+    /// LUTs, gradients or animation keyframes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use map_range::MapRange;
+    /// let values: Vec<f32> = f32::map_range_iter(0.0..1.0, 0.0..10.0, 3).collect();
+    /// assert_eq!(values, [0.0, 5.0, 10.0]);
+    /// ```
+    #[must_use]
+    fn map_range_iter(from: Range<Self>, to: Range<Self>, steps: usize) -> MapRangeIter<Self>
+    where
+        Self: Copy + 'static + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>,
+        usize: AsPrimitive<Self>,
+    {
+        MapRangeIter {
+            from_start: from.start,
+            from_end: from.end,
+            to_start: to.start,
+            to_end: to.end,
+            steps,
+            index: 0,
+        }
+    }
+}
+
+/// Lazy iterator returned by [`MapRange::map_range_iter`].
+pub struct MapRangeIter<T> {
+    from_start: T,
+    from_end: T,
+    to_start: T,
+    to_end: T,
+    steps: usize,
+    index: usize,
+}
+
+/// Resolves the start bound of a [`RangeBounds`] to a concrete inclusive value.
+fn resolve_start<T: Copy + One + Add<Output = T> + Bounded>(bound: Bound<&T>) -> T {
+    match bound {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => *start + T::one(),
+        Bound::Unbounded => T::min_value(),
+    }
+}
+
+/// Resolves the end bound of a [`RangeBounds`] to the value [`MapRange::map_range_inclusive`]
+/// expects, which is the bound's value unchanged whether it's included or excluded (see
+/// [`MapRange::map_range_bounds`] for why no `- one()` adjustment belongs here).
+fn resolve_end<T: Copy + Bounded>(bound: Bound<&T>) -> T {
+    match bound {
+        Bound::Included(end) | Bound::Excluded(end) => *end,
+        Bound::Unbounded => T::max_value(),
+    }
 }
 
 /// Mapping a value from range `from`
@@ -82,20 +218,213 @@ pub trait MapRange: Sized {
 /// assert_eq!(a, None);
 /// assert_eq!(b, Some(8));
 /// ```
+///
+/// Implemented for any `T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv`.
+/// `num_traits` doesn't implement those checked-arithmetic traits for `f32`/
+/// `f64`, so this blanket impl doesn't cover floats — but a `checked_map_range_f32`
+/// / `checked_map_range_f64` `const fn` (returning `None` only when `from` is
+/// empty) is still available as a standalone function, alongside the integer
+/// `const fn`s, for the same reason described on [`MapRange`].
 pub trait CheckedMapRange: Sized {
     #[must_use]
     fn checked_map_range(self, from: Range<Self>, to: Range<Self>) -> Option<Self>;
+
+    /// Checked version of [`MapRange::map_range_inclusive`].
+    #[must_use]
+    fn checked_map_range_inclusive(
+        self,
+        from: RangeInclusive<Self>,
+        to: RangeInclusive<Self>,
+    ) -> Option<Self> {
+        let (from_start, from_end) = from.into_inner();
+        let (to_start, to_end) = to.into_inner();
+        self.checked_map_range(from_start..from_end, to_start..to_end)
+    }
+
+    /// Checked version of [`MapRange::map_range_bounds`].
+    #[must_use]
+    fn checked_map_range_bounds<F, T>(self, from: F, to: T) -> Option<Self>
+    where
+        Self: Copy + One + Bounded + Add<Output = Self>,
+        F: RangeBounds<Self>,
+        T: RangeBounds<Self>,
+    {
+        let from = resolve_start(from.start_bound())..=resolve_end(from.end_bound());
+        let to = resolve_start(to.start_bound())..=resolve_end(to.end_bound());
+        self.checked_map_range_inclusive(from, to)
+    }
+}
+
+/// Mapping a value from range `from` to another range `to`, saturating
+/// into `to` instead of extrapolating beyond it.
+///
+/// This is useful for normalizing sensor/input values where anything
+/// outside of `from` should pin to the edges of `to` rather than
+/// producing a value outside of it.
+///
+/// # Examples
+///
+/// ```
+/// # use map_range::ClampedMapRange;
+/// assert_eq!(10_i32.clamped_map_range(0..5, 0..10), 10);
+/// assert_eq!((-10_i32).clamped_map_range(0..5, 0..10), 0);
+/// assert_eq!(5.0_f32.clamped_map_range(0.0..10.0, 10.0..0.0), 5.0);
+/// ```
+pub trait ClampedMapRange: Sized {
+    #[must_use]
+    fn clamped_map_range(self, from: Range<Self>, to: Range<Self>) -> Self;
+}
+
+/// Mapping a value from range `from` to another range `to`, saturating the
+/// intermediate arithmetic at each step instead of panicking on overflow.
+///
+/// [`MapRange::map_range`] multiplies before it divides, so it can overflow
+/// long before the final result would actually be out of bounds (see its
+/// `200_u8.map_range(0..10, 0..20)` example). This degrades gracefully
+/// instead: every add/sub/mul step saturates to the type's min/max via
+/// [`SaturatingAdd`], [`SaturatingSub`] and [`SaturatingMul`], and the final
+/// result is clamped into `to` (oriented the same way as
+/// [`ClampedMapRange`], so a descending `to` still works). Dividing by an
+/// empty `from` is still nonsensical rather than saturating, so that step
+/// uses [`CheckedDiv`] and panics, same as [`MapRange::map_range`].
+///
+/// # Panics
+///
+/// Panics if `from.end == from.start`.
+///
+/// # Examples
+///
+/// ```
+/// # use map_range::SaturatingMapRange;
+/// // plain `map_range` would overflow multiplying `200 * 20` as a `u8`;
+/// // this saturates instead and clamps into `to`.
+/// assert_eq!(200_u8.saturating_map_range(0..10, 0..20), 20);
+/// ```
+pub trait SaturatingMapRange: Sized {
+    #[must_use]
+    fn saturating_map_range(self, from: Range<Self>, to: Range<Self>) -> Self;
+}
+
+/// Mapping a value of one numeric type from range `from`
+/// to another range `to` of a possibly different numeric type.
+///
+/// This is the generic counterpart of [`MapRange`] for when `self`, `from`
+/// and `to` are not all the same type, e.g. mapping a `u16` ADC sample into
+/// a `0.0..1.0` `f32` range.
+///
+/// `self`, `from` and `to` are all converted into `f64` via [`AsPrimitive`]
+/// and the normalized position and scaling happen entirely in that `f64`
+/// domain, so a fractional position (e.g. mapping `0.9` into `0..255`) is no
+/// longer thrown away before it ever reaches the scale step, and the result
+/// is truncated towards zero only once, at the final `f64 -> To` cast, same
+/// as any other `as` numeric cast when `To` is an integer. This does *not*
+/// make the mapping exact for every domain, though: `f64` only has a 53-bit
+/// mantissa, so `u64`/`i64`/`u128`/`i128` values or spans beyond `2^53` can
+/// themselves lose precision going through the `f64` intermediate.
+///
+/// # Examples
+///
+/// ```
+/// # use map_range::MapRangeInto;
+/// let sample: u16 = 32768;
+/// let normalized: f32 = sample.map_range_into(0..u16::MAX, 0.0..1.0);
+///
+/// assert!((normalized - 0.5).abs() < 0.001);
+/// ```
+pub trait MapRangeInto<From, To> {
+    #[must_use]
+    fn map_range_into(self, from: Range<From>, to: Range<To>) -> To;
 }
 
 //
 
+// shamelessly stolen from my own code:
+// https://github.com/Overpeek/overpeek-engine/blob/3df11072378ba870033a19cd09fb332bcc4c466d/src/engine/utility/extra.hpp
+//
+// Generated as free `const fn`s per primitive (trait methods can't be
+// `const` yet). These duplicate the `MapRange`/`CheckedMapRange` formula
+// rather than backing those traits' impls: the traits keep a generic
+// blanket impl as their ergonomic path below, and a blanket impl can't
+// coexist with per-type impls for the same types without specialization.
+macro_rules! impl_primitive_map_range_int {
+    ($(($t:ty, $map_fn:ident, $checked_fn:ident)),* $(,)?) => {
+        $(
+            #[doc = concat!("`const fn` equivalent of [`MapRange::map_range`] for [`", stringify!($t), "`].")]
+            #[must_use]
+            pub const fn $map_fn(value: $t, from: Range<$t>, to: Range<$t>) -> $t {
+                let Range { start: from_start, end: from_end } = from;
+                let Range { start: to_start, end: to_end } = to;
+                to_start + (value - from_start) * (to_end - to_start) / (from_end - from_start)
+            }
+
+            #[doc = concat!("`const fn` equivalent of [`CheckedMapRange::checked_map_range`] for [`", stringify!($t), "`].")]
+            #[must_use]
+            pub const fn $checked_fn(value: $t, from: Range<$t>, to: Range<$t>) -> Option<$t> {
+                let Range { start: from_start, end: from_end } = from;
+                let Range { start: to_start, end: to_end } = to;
+
+                let Some(diff) = value.checked_sub(from_start) else { return None };
+                let Some(to_span) = to_end.checked_sub(to_start) else { return None };
+                let Some(scaled) = diff.checked_mul(to_span) else { return None };
+                let Some(from_span) = from_end.checked_sub(from_start) else { return None };
+                let Some(normalized) = scaled.checked_div(from_span) else { return None };
+                to_start.checked_add(normalized)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_primitive_map_range_float {
+    ($(($t:ty, $map_fn:ident, $checked_fn:ident)),* $(,)?) => {
+        $(
+            #[doc = concat!("`const fn` equivalent of [`MapRange::map_range`] for [`", stringify!($t), "`].")]
+            #[must_use]
+            pub const fn $map_fn(value: $t, from: Range<$t>, to: Range<$t>) -> $t {
+                let Range { start: from_start, end: from_end } = from;
+                let Range { start: to_start, end: to_end } = to;
+                to_start + (value - from_start) * (to_end - to_start) / (from_end - from_start)
+            }
+
+            #[doc = concat!(
+                "`const fn` equivalent of [`CheckedMapRange::checked_map_range`] for [`",
+                stringify!($t),
+                "`], returning `None` only when `from` is empty.",
+            )]
+            #[must_use]
+            pub const fn $checked_fn(value: $t, from: Range<$t>, to: Range<$t>) -> Option<$t> {
+                let Range { start: from_start, end: from_end } = from;
+                if from_end - from_start == 0.0 {
+                    return None;
+                }
+                Some($map_fn(value, from_start..from_end, to))
+            }
+        )*
+    };
+}
+
+impl_primitive_map_range_int!(
+    (i8, map_range_i8, checked_map_range_i8),
+    (i16, map_range_i16, checked_map_range_i16),
+    (i32, map_range_i32, checked_map_range_i32),
+    (i64, map_range_i64, checked_map_range_i64),
+    (i128, map_range_i128, checked_map_range_i128),
+    (u8, map_range_u8, checked_map_range_u8),
+    (u16, map_range_u16, checked_map_range_u16),
+    (u32, map_range_u32, checked_map_range_u32),
+    (u64, map_range_u64, checked_map_range_u64),
+    (u128, map_range_u128, checked_map_range_u128),
+);
+
+impl_primitive_map_range_float!(
+    (f32, map_range_f32, checked_map_range_f32),
+    (f64, map_range_f64, checked_map_range_f64),
+);
+
 impl<T> MapRange for T
 where
     T: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>,
 {
     fn map_range(self, from: Range<Self>, to: Range<Self>) -> Self {
-        // shamelessly stolen from my own code:
-        // https://github.com/Overpeek/overpeek-engine/blob/3df11072378ba870033a19cd09fb332bcc4c466d/src/engine/utility/extra.hpp
         to.start + (self - from.start) * (to.end - to.start) / (from.end - from.start)
     }
 }
@@ -117,11 +446,126 @@ where
     }
 }
 
+impl<T> ClampedMapRange for T
+where
+    T: Copy + MapRange + PartialOrd,
+{
+    fn clamped_map_range(self, from: Range<Self>, to: Range<Self>) -> Self {
+        let (from_lo, from_hi) = ordered(from.start, from.end);
+        let (to_lo, to_hi) = ordered(to.start, to.end);
+        let clamped_self = clamp(self, from_lo, from_hi);
+        clamp(clamped_self.map_range(from, to), to_lo, to_hi)
+    }
+}
+
+impl<T> SaturatingMapRange for T
+where
+    T: Copy + PartialOrd + SaturatingAdd + SaturatingSub + SaturatingMul + CheckedDiv,
+{
+    fn saturating_map_range(self, from: Range<Self>, to: Range<Self>) -> Self {
+        let diff = self.saturating_sub(&from.start);
+        let to_span = to.end.saturating_sub(&to.start);
+        let scaled = diff.saturating_mul(&to_span);
+        let from_span = from.end.saturating_sub(&from.start);
+        let normalized = scaled
+            .checked_div(&from_span)
+            .expect("`from` must not be empty");
+
+        let (to_lo, to_hi) = ordered(to.start, to.end);
+        clamp(to.start.saturating_add(&normalized), to_lo, to_hi)
+    }
+}
+
+impl<T> Iterator for MapRangeIter<T>
+where
+    T: MapRange + Copy + 'static + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    usize: AsPrimitive<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index >= self.steps {
+            return None;
+        }
+
+        let index = self.index;
+        self.index += 1;
+
+        // First/last are returned directly instead of through the general
+        // formula below, which isn't guaranteed to round back to exactly
+        // `from_end` (and from there `to_end`) for floats.
+        if index == 0 {
+            return Some(self.to_start);
+        }
+        if index == self.steps - 1 {
+            return Some(self.to_end);
+        }
+
+        let divisor = self.steps.saturating_sub(1).max(1);
+        let source =
+            self.from_start + (self.from_end - self.from_start) * index.as_() / divisor.as_();
+
+        Some(source.map_range(self.from_start..self.from_end, self.to_start..self.to_end))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.steps - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for MapRangeIter<T>
+where
+    T: MapRange + Copy + 'static + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    usize: AsPrimitive<T>,
+{
+}
+
+impl<From, To> MapRangeInto<From, To> for From
+where
+    From: Copy + AsPrimitive<f64>,
+    To: Copy + 'static + AsPrimitive<f64>,
+    f64: AsPrimitive<To>,
+{
+    fn map_range_into(self, from: Range<From>, to: Range<To>) -> To {
+        let from_start: f64 = from.start.as_();
+        let from_end: f64 = from.end.as_();
+        let to_start: f64 = to.start.as_();
+        let to_end: f64 = to.end.as_();
+
+        let self_f: f64 = self.as_();
+        let normalized = (self_f - from_start) / (from_end - from_start);
+        (to_start + normalized * (to_end - to_start)).as_()
+    }
+}
+
+/// Orders a pair of possibly-descending bounds as `(lo, hi)`.
+///
+/// `PartialOrd` is used instead of `Ord` so that this works for floats too.
+fn ordered<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Clamps `value` into `[lo, hi]` using `PartialOrd` so that floats are supported.
+fn clamp<T: PartialOrd>(value: T, lo: T, hi: T) -> T {
+    if value < lo {
+        lo
+    } else if value > hi {
+        hi
+    } else {
+        value
+    }
+}
+
 //
 
 #[cfg(test)]
 mod tests {
-    use crate::MapRange;
+    use crate::{ClampedMapRange, MapRange, MapRangeInto, SaturatingMapRange};
 
     #[test]
     fn test_f32_map() {
@@ -133,4 +577,115 @@ mod tests {
     fn test_i32_map() {
         assert_eq!(5_i32.map_range(0..10, -10..10), 0);
     }
+
+    #[test]
+    fn test_inclusive_map() {
+        assert_eq!(0_u8.map_range_inclusive(0..=9, 0..=20), 0);
+        assert_eq!(9_u8.map_range_inclusive(0..=9, 0..=20), 20);
+    }
+
+    #[test]
+    fn test_bounds_map() {
+        assert_eq!(0_u8.map_range_bounds(.., 0..=20), 0);
+        assert_eq!(9_u8.map_range_bounds(0..=9, 0..=20), 20);
+    }
+
+    #[test]
+    fn test_bounds_map_half_open_agrees_with_map_range() {
+        // A plain (half-open) `Range` must resolve identically through
+        // `map_range_bounds` as it does through `map_range` directly.
+        for value in 0..10_u8 {
+            assert_eq!(
+                value.map_range_bounds(0..10, 0..20),
+                value.map_range(0..10, 0..20),
+            );
+        }
+    }
+
+    #[test]
+    fn test_bounds_map_half_open_float() {
+        // Excluded float ends have no discrete "last value before the end",
+        // so they must be used as-is rather than offset by one.
+        assert_eq!(
+            5.0_f32.map_range_bounds(0.0..10.0, 0.0..1.0),
+            5.0_f32.map_range(0.0..10.0, 0.0..1.0),
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_clamped_map() {
+        assert_eq!(10_i32.clamped_map_range(0..5, 0..10), 10);
+        assert_eq!((-10_i32).clamped_map_range(0..5, 0..10), 0);
+        assert_eq!(10_i32.clamped_map_range(0..5, 10..0), 0);
+        assert_eq!(2_i32.clamped_map_range(0..5, 0..10), 4);
+    }
+
+    #[test]
+    fn test_map_range_into() {
+        let normalized: f32 = 32768_u16.map_range_into(0..u16::MAX, 0.0..1.0);
+        assert!((normalized - 0.5).abs() < 0.001);
+
+        // The normalized position is computed in `f64` before scaling, so a
+        // fractional input isn't thrown away just because `To` is an integer
+        // — only the final cast truncates.
+        let sample: u8 = 0.9_f32.map_range_into(0.0..1.0, 0..255);
+        assert_eq!(sample, 229);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_const_fn_map_range() {
+        const MAPPED: i32 = crate::map_range_i32(5, 0..10, -10..10);
+        assert_eq!(MAPPED, 0);
+
+        const CHECKED: Option<u32> = crate::checked_map_range_u32(10, 0..5, 5..2);
+        assert_eq!(CHECKED, None);
+
+        const CHECKED_F32: Option<f32> = crate::checked_map_range_f32(5.0, 0.0..0.0, 0.0..1.0);
+        assert_eq!(CHECKED_F32, None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_saturating_map() {
+        // plain `map_range` would overflow computing `200 * 20` as a `u8`.
+        assert_eq!(200_u8.saturating_map_range(0..10, 0..20), 20);
+        assert_eq!(5_u8.saturating_map_range(0..10, 0..20), 10);
+        // descending `to` is still oriented correctly (needs a signed type,
+        // since an unsigned span can't go negative in the first place).
+        assert_eq!(200_i32.saturating_map_range(0..10, 20..0), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_saturating_map_empty_from() {
+        let _ = 5_i32.saturating_map_range(0..0, 0..10);
+    }
+
+    #[test]
+    fn test_map_range_iter() {
+        let mut iter = f32::map_range_iter(0.0..1.0, 0.0..10.0, 3);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(0.0));
+        assert_eq!(iter.next(), Some(5.0));
+        assert_eq!(iter.next(), Some(10.0));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = i32::map_range_iter(0..10, 0..100, 2);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(100));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_map_range_iter_last_is_exact() {
+        // A step count that doesn't divide the span evenly is exactly the
+        // case where `from_start + (from_end - from_start) * (steps - 1) /
+        // (steps - 1)` could drift by an ULP before even reaching `map_range`.
+        let mut iter = f32::map_range_iter(0.0..3.0, 0.0..1.0, 7);
+        assert_eq!(iter.next(), Some(0.0));
+        assert_eq!(iter.last(), Some(1.0));
+    }
 }